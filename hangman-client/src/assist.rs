@@ -0,0 +1,82 @@
+use read_input::{shortcut::input, InputBuild};
+
+use crate::solver::{Strategy, StrategicSolverFactory};
+
+/// Runs an interactive REPL that suggests letters for a human playing
+/// hangman somewhere else (on paper, with a friend, whatever). The user
+/// feeds back the revealed pattern and whether the suggestion hit or
+/// missed; the solver has no server to infer that from.
+pub fn run(
+    dictionary: &str,
+    length: usize,
+    entropy: bool,
+    hard_mode: Option<usize>,
+) -> anyhow::Result<()> {
+    let strategy = if entropy {
+        Strategy::Entropy
+    } else {
+        Strategy::Frequency
+    };
+
+    let factory = StrategicSolverFactory::from_path(dictionary)?.with_strategy(strategy);
+    let factory = match hard_mode {
+        Some(threshold) => factory.with_hard_mode(threshold),
+        None => factory,
+    };
+    let mut assist = factory.into_assist(length);
+
+    loop {
+        if assist.is_solved() {
+            println!("Solved: {}", assist.pattern());
+            break;
+        }
+
+        let letter = assist.suggest();
+        println!("Try: {letter}");
+
+        let candidates = assist.candidates();
+        if !candidates.is_empty() {
+            println!(
+                "{} candidate(s) remaining: {}",
+                assist.candidate_count(),
+                candidates.join(", ")
+            );
+        }
+
+        let command: String = input()
+            .msg("revealed pattern, 'undo', or 'restart <length>': ")
+            .get();
+
+        match command.trim() {
+            "undo" => {
+                if !assist.undo() {
+                    println!("Nothing to undo.");
+                }
+            }
+            command if command.starts_with("restart") => {
+                let len = command
+                    .trim_start_matches("restart")
+                    .trim()
+                    .parse()
+                    .unwrap_or(length);
+                assist.restart(len);
+            }
+            revealed => {
+                if !assist.set_pattern(revealed.trim()) {
+                    println!("A revealed pattern can only contain letters and '*'.");
+                    continue;
+                }
+
+                let miss: String = input().msg("was that a miss? (y/n): ").get();
+
+                if miss.trim().eq_ignore_ascii_case("y") {
+                    assist.record_miss();
+                } else {
+                    assist.record_hit();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use hangman::Game;
+use hashbrown::HashMap;
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+use serde::Serialize;
+use squirrel_rng::SquirrelRng;
+
+use crate::solver::Solver;
+
+/// Aggregate results of running a solver against a batch of local games.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub attempts: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub mean_incorrect: f64,
+    pub median_incorrect: f64,
+    /// guesses remaining at the moment of victory, bucketed by count
+    pub guesses_remaining_histogram: Vec<(i32, usize)>,
+    pub worst_case_words: Vec<String>,
+}
+
+struct Outcome {
+    word: String,
+    won: bool,
+    incorrect: usize,
+    /// letters of the word correctly revealed by the time the game ended;
+    /// for a loss, this is the only thing that distinguishes "close" misses
+    /// from ones where the solver never got anywhere, since `incorrect` is
+    /// always `MAX_INCORRECT` once the game is lost.
+    correct: usize,
+    guesses_remaining: i32,
+}
+
+/// Chooses `samples` words at random from `dictionary`, or the whole
+/// dictionary when `samples` is `None`.
+pub fn choose_words(dictionary: &[String], samples: Option<usize>) -> Vec<String> {
+    match samples {
+        Some(n) => {
+            let mut rng = SquirrelRng::with_seed(3408509824);
+            dictionary.choose_multiple(&mut rng, n).cloned().collect()
+        }
+        None => dictionary.to_vec(),
+    }
+}
+
+/// Plays every word in `words` against a fresh solver built by
+/// `build_solver`, using the `hangman` lib's `Game` directly rather than a
+/// running server. `build_solver` is called once per word so each attempt
+/// gets its own solver, keeping rayon's arbitrary scheduling from letting one
+/// game's guesses leak into another's.
+pub fn run<F>(words: &[String], build_solver: F) -> Report
+where
+    F: Fn() -> Box<dyn Solver> + Sync,
+{
+    let outcomes: Vec<Outcome> = words
+        .par_iter()
+        .map(|word| play(word, &mut *build_solver()))
+        .collect();
+
+    summarize(outcomes)
+}
+
+fn play(word: &str, solver: &mut dyn Solver) -> Outcome {
+    let mut game = Game::honest(word.to_owned());
+    let mut masked = game.masked_word();
+
+    loop {
+        if game.is_won() || game.is_lost() {
+            return Outcome {
+                word: word.to_owned(),
+                won: game.is_won(),
+                incorrect: game.incorrect().len(),
+                correct: game.correct().len(),
+                guesses_remaining: game.guesses_remaining(),
+            };
+        }
+
+        let letter = solver.next_letter(&masked, game.guesses_remaining() as usize);
+        game.guess(letter.to_ascii_uppercase() as u8);
+        masked = game.masked_word();
+    }
+}
+
+fn summarize(mut outcomes: Vec<Outcome>) -> Report {
+    let attempts = outcomes.len();
+    let wins = outcomes.iter().filter(|outcome| outcome.won).count();
+
+    let mut incorrect: Vec<usize> = outcomes.iter().map(|outcome| outcome.incorrect).collect();
+    incorrect.sort_unstable();
+
+    let mean_incorrect = if attempts == 0 {
+        0.0
+    } else {
+        incorrect.iter().sum::<usize>() as f64 / attempts as f64
+    };
+
+    let mut histogram: HashMap<i32, usize> = HashMap::new();
+    for outcome in outcomes.iter().filter(|outcome| outcome.won) {
+        *histogram.entry(outcome.guesses_remaining).or_insert(0) += 1;
+    }
+    let mut guesses_remaining_histogram: Vec<_> = histogram.into_iter().collect();
+    guesses_remaining_histogram.sort_unstable_by_key(|&(guesses, _)| guesses);
+
+    // Every loss is charged the same `incorrect` count (that's what makes it
+    // a loss), so ranking "worst" losses by it never discriminates. Instead,
+    // the worst losses are the ones where the solver revealed the fewest
+    // correct letters before running out of guesses.
+    outcomes.sort_unstable_by_key(|outcome| (outcome.won, outcome.correct));
+    let worst_case_words = outcomes
+        .iter()
+        .filter(|outcome| !outcome.won)
+        .take(10)
+        .map(|outcome| outcome.word.clone())
+        .collect();
+
+    Report {
+        attempts,
+        wins,
+        win_rate: if attempts == 0 {
+            0.0
+        } else {
+            wins as f64 / attempts as f64
+        },
+        mean_incorrect,
+        median_incorrect: median(&incorrect),
+        guesses_remaining_histogram,
+        worst_case_words,
+    }
+}
+
+fn median(sorted: &[usize]) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) as f64 / 2.0
+    } else {
+        sorted[mid] as f64
+    }
+}
@@ -3,51 +3,127 @@ use std::{io, process};
 use clap::{Parser, Subcommand};
 use hangman::{CreateGameResponse, UpdateGameRequest, UpdateGameResponse};
 use reqwest::{blocking::Client, StatusCode};
-use solver::{RandomSolver, Solver, StrategicSolverFactory, UserInputSolver};
+use solver::{RandomSolver, Solver, Strategy, StrategicSolverFactory, UserInputSolver};
 
+mod assist;
+mod bench;
 mod solver;
 
 #[derive(Debug, Parser)]
 struct Args {
-    /// hangman url
-    server: String,
-
     #[clap(subcommand)]
     command: Command,
 }
 
 #[derive(Debug, Subcommand)]
 enum Command {
-    Random,
-    Strategic(SolverConfig),
-    User,
+    Random(PlayArgs),
+    Strategic(StrategicArgs),
+    User(PlayArgs),
+    Bench(BenchConfig),
+    Assist(AssistConfig),
+}
+
+#[derive(Debug, Parser)]
+struct PlayArgs {
+    /// hangman url
+    server: String,
 }
 
 #[derive(Debug, Parser)]
-struct SolverConfig {
+struct StrategicArgs {
+    /// hangman url
+    server: String,
+
     /// path to dictionary
     dictionary: String,
+
+    /// pick letters by expected information gain (Shannon entropy) instead
+    /// of raw letter frequency
+    #[clap(long)]
+    entropy: bool,
+
+    /// once this many or fewer candidate words remain, guess letters that
+    /// distinguish between them directly instead of by frequency/entropy
+    #[clap(long)]
+    hard_mode: Option<usize>,
+}
+
+#[derive(Debug, Parser)]
+struct BenchConfig {
+    /// path to dictionary; also supplies the secret words played against
+    dictionary: String,
+
+    /// number of random words to sample; omit to benchmark the whole
+    /// dictionary
+    #[clap(long)]
+    samples: Option<usize>,
+
+    /// benchmark the random solver instead of the strategic one
+    #[clap(long)]
+    random: bool,
+
+    /// use entropy-based letter selection for the strategic solver
+    #[clap(long)]
+    entropy: bool,
+
+    /// once this many or fewer candidate words remain, guess letters that
+    /// distinguish between them directly instead of by frequency/entropy
+    #[clap(long)]
+    hard_mode: Option<usize>,
+}
+
+#[derive(Debug, Parser)]
+struct AssistConfig {
+    /// path to dictionary
+    dictionary: String,
+
+    /// length of the word you're trying to guess
+    length: usize,
+
+    /// use entropy-based letter selection instead of raw frequency
+    #[clap(long)]
+    entropy: bool,
+
+    /// once this many or fewer candidate words remain, guess letters that
+    /// distinguish between them directly instead of by frequency/entropy
+    #[clap(long)]
+    hard_mode: Option<usize>,
 }
 
 fn main() {
-    if let Err(e) = run(&Args::parse()) {
+    let args = Args::parse();
+
+    let result = match &args.command {
+        Command::Bench(config) => run_bench(config),
+        Command::Assist(config) => assist::run(
+            &config.dictionary,
+            config.length,
+            config.entropy,
+            config.hard_mode,
+        ),
+        command => run(command),
+    };
+
+    if let Err(e) = result {
         eprintln!("{e}");
         process::exit(1);
     }
 }
 
-fn run(args: &Args) -> anyhow::Result<()> {
+fn run(command: &Command) -> anyhow::Result<()> {
+    let server = server_url(command);
     let client = Client::builder()
         .user_agent(concat!("hangman-client v", env!("CARGO_PKG_VERSION")))
         .build()
         .unwrap();
 
-    let CreateGameResponse { id, word, guesses } = client.get(&args.server).send()?.json()?;
-    let game_url = format!("{}/{}", args.server, id);
+    let CreateGameResponse { id, word, guesses } = client.get(server).send()?.json()?;
+    let game_url = format!("{server}/{id}");
 
     let mut word = word;
     let mut guesses_remaining = guesses as usize;
-    let mut solver = build_solver(&args.command)?;
+    let mut solver = build_solver(command)?;
 
     loop {
         let letter = solver.next_letter(&word, guesses_remaining).to_string();
@@ -87,12 +163,63 @@ fn run(args: &Args) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn server_url(command: &Command) -> &str {
+    match command {
+        Command::Random(args) | Command::User(args) => &args.server,
+        Command::Strategic(args) => &args.server,
+        Command::Bench(_) | Command::Assist(_) => {
+            unreachable!("bench and assist run locally and never need a server url")
+        }
+    }
+}
+
 fn build_solver(command: &Command) -> io::Result<Box<dyn Solver>> {
     match command {
-        Command::Random => Ok(Box::new(RandomSolver::new())),
-        Command::Strategic(config) => Ok(Box::new(
-            StrategicSolverFactory::from_path(&config.dictionary)?.into_solver(),
-        )),
-        Command::User => Ok(Box::new(UserInputSolver)),
+        Command::Random(_) => Ok(Box::new(RandomSolver::new())),
+        Command::Strategic(args) => {
+            let strategy = if args.entropy {
+                Strategy::Entropy
+            } else {
+                Strategy::Frequency
+            };
+
+            let factory =
+                StrategicSolverFactory::from_path(&args.dictionary)?.with_strategy(strategy);
+            let factory = match args.hard_mode {
+                Some(threshold) => factory.with_hard_mode(threshold),
+                None => factory,
+            };
+
+            Ok(Box::new(factory.into_solver()))
+        }
+        Command::User(_) => Ok(Box::new(UserInputSolver)),
+        Command::Bench(_) | Command::Assist(_) => {
+            unreachable!("bench and assist build their own solver state")
+        }
     }
 }
+
+fn run_bench(config: &BenchConfig) -> anyhow::Result<()> {
+    let strategy = if config.entropy {
+        Strategy::Entropy
+    } else {
+        Strategy::Frequency
+    };
+
+    let factory = StrategicSolverFactory::from_path(&config.dictionary)?.with_strategy(strategy);
+    let factory = match config.hard_mode {
+        Some(threshold) => factory.with_hard_mode(threshold),
+        None => factory,
+    };
+    let words = bench::choose_words(factory.words(), config.samples);
+
+    let report = if config.random {
+        bench::run(&words, || Box::new(RandomSolver::with_seed(3408509824)))
+    } else {
+        bench::run(&words, || Box::new(factory.clone().into_solver()))
+    };
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ mod strategic;
 mod user;
 
 pub use random::RandomSolver;
-pub use strategic::StrategicSolverFactory;
+pub use strategic::{Assist, Strategy, StrategicSolverFactory};
 pub use user::UserInputSolver;
 
 pub trait Solver {
@@ -10,8 +10,18 @@ pub struct RandomSolver {
 
 impl RandomSolver {
     pub fn new() -> Self {
+        Self::with_rng(&mut SquirrelRng::new())
+    }
+
+    /// Builds a solver whose guess order is shuffled deterministically from
+    /// `seed`, useful for reproducible benchmark runs.
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(&mut SquirrelRng::with_seed(seed))
+    }
+
+    fn with_rng(rng: &mut SquirrelRng) -> Self {
         let mut alpha: Vec<_> = b"abcdefghijklmnopqrstuvwxyz".iter().copied().collect();
-        alpha.shuffle(&mut SquirrelRng::new());
+        alpha.shuffle(rng);
         Self { idx: 0, alpha }
     }
 
@@ -1,4 +1,4 @@
-use std::{cmp::Reverse, fs, io};
+use std::{cmp::Reverse, fs, io, sync::Arc};
 
 use hashbrown::{HashMap, HashSet};
 use rand::seq::{IteratorRandom, SliceRandom};
@@ -7,8 +7,33 @@ use squirrel_rng::SquirrelRng;
 
 use super::Solver;
 
+/// How `SolverState` picks the next letter to guess.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Picks among the most-frequent letters in the filtered dictionary.
+    #[default]
+    Frequency,
+    /// Picks the letter that maximizes expected information gain (Shannon
+    /// entropy of the partition it induces over the filtered dictionary).
+    Entropy,
+}
+
+/// Once the surviving candidate set shrinks to this size or smaller, it gets
+/// listed alongside the usual suggestion so the caller can see the solver is
+/// about to pin the answer down.
+const CANDIDATE_LISTING_THRESHOLD: usize = 10;
+
+/// Entropies within this margin of each other are treated as tied. Summing
+/// a `HashMap`'s values isn't associative across iteration orders, so exact
+/// `f64` equality would let hash-bucket order silently split ties that are
+/// mathematically equal.
+const ENTROPY_EPSILON: f64 = 1e-9;
+
+#[derive(Clone)]
 pub struct StrategicSolverFactory {
-    dictionary: Vec<String>,
+    dictionary: Arc<[String]>,
+    strategy: Strategy,
+    hard_mode_threshold: Option<usize>,
 }
 
 impl StrategicSolverFactory {
@@ -25,20 +50,68 @@ impl StrategicSolverFactory {
             .collect();
 
         dictionary.sort_unstable();
-        Self { dictionary }
+        Self {
+            dictionary: dictionary.into(),
+            strategy: Strategy::default(),
+            hard_mode_threshold: None,
+        }
+    }
+
+    /// Selects the letter-picking strategy the resulting solver will use.
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Once the candidate set still matching the revealed pattern shrinks to
+    /// `threshold` words or fewer, switches from frequency/entropy letter
+    /// selection to directly guessing letters that distinguish between
+    /// those candidates, and commits to finishing out the word once only
+    /// one candidate remains.
+    pub fn with_hard_mode(mut self, threshold: usize) -> Self {
+        self.hard_mode_threshold = Some(threshold);
+        self
+    }
+
+    /// The filtered, uppercased dictionary backing this factory's solvers.
+    pub fn words(&self) -> &[String] {
+        &self.dictionary
     }
 
     #[allow(unused)]
     pub fn solver<'a>(&'a self) -> StrategicSolver<'a> {
         StrategicSolver {
             dictionary: &self.dictionary,
-            ..Default::default()
+            state: self.new_state(),
         }
     }
 
     pub fn into_solver(self) -> IntoStrategicSolver {
         IntoStrategicSolver {
+            state: self.new_state(),
             dictionary: self.dictionary,
+        }
+    }
+
+    /// Builds an `Assist` session for a word of `length` letters: the same
+    /// letter-picking logic as `into_solver`, but driven by explicit
+    /// user-supplied patterns and hit/miss feedback instead of a server's
+    /// `UpdateGameResponse`.
+    pub fn into_assist(self, length: usize) -> Assist {
+        Assist {
+            state: self.new_state(),
+            dictionary: self.dictionary,
+            strategy: self.strategy,
+            hard_mode_threshold: self.hard_mode_threshold,
+            pattern: "*".repeat(length),
+            history: Vec::new(),
+        }
+    }
+
+    fn new_state(&self) -> SolverState {
+        SolverState {
+            strategy: self.strategy,
+            hard_mode_threshold: self.hard_mode_threshold,
             ..Default::default()
         }
     }
@@ -46,7 +119,7 @@ impl StrategicSolverFactory {
 
 #[derive(Debug, Default)]
 pub struct IntoStrategicSolver {
-    dictionary: Vec<String>,
+    dictionary: Arc<[String]>,
     state: SolverState,
 }
 
@@ -55,32 +128,93 @@ struct SolverState {
     submitted: HashSet<u8>,
     uncharacterized: Option<u8>,
     disallow: HashSet<u8>,
+    strategy: Strategy,
+    hard_mode_threshold: Option<usize>,
     rng: SquirrelRng,
+    candidate_count: usize,
+    candidates: Vec<String>,
 }
 
 impl SolverState {
-    fn next<T: AsRef<str>>(
-        &mut self,
-        word: &str,
-        _guesses_remaining: usize,
-        dictionary: &[T],
-    ) -> char {
-        println!("{word}");
-
-        self.characterize(word);
-
+    /// Picks the next letter to guess given the current revealed `pattern`
+    /// (`*` for unknown positions) and the surviving `dictionary`. Does not
+    /// touch hit/miss bookkeeping on its own - call `record_feedback` (or
+    /// `characterize`, for the server-driven contract) once the previous
+    /// guess's outcome is known.
+    fn suggest<T: AsRef<str>>(&mut self, pattern: &str, dictionary: &[T]) -> char {
         let expr = Shape {
-            expr: build_expr(word).unwrap(),
+            expr: build_expr(pattern).unwrap(),
             disallow: &self.disallow,
         };
 
-        let filtered_dictionary = dictionary
+        let filtered_dictionary: Vec<&str> = dictionary
             .iter()
             .map(|s| s.as_ref())
-            .filter(|&text| expr.filter(text));
+            .filter(|&text| expr.filter(text))
+            .collect();
+
+        self.report_candidates(&filtered_dictionary);
+
+        if let Some(selected) = self.hard_mode_pick(&filtered_dictionary) {
+            self.uncharacterized = Some(selected);
+            return selected as char;
+        }
+
+        let first_rank = match self.strategy {
+            Strategy::Frequency => self.rank_by_frequency(&filtered_dictionary),
+            Strategy::Entropy => self.rank_by_entropy(&filtered_dictionary),
+        };
 
+        let selected = first_rank
+            .choose(&mut self.rng)
+            .copied()
+            .unwrap_or_else(|| (b'A'..=b'Z').choose(&mut self.rng).unwrap());
+
+        self.uncharacterized = Some(selected);
+        selected as char
+    }
+
+    /// Records how many words still match the revealed pattern, and lists
+    /// them once the set is small enough to be interesting. Purely
+    /// bookkeeping - read it back with `candidate_count`/`candidates` and
+    /// print it if wanted; this does not print anything itself.
+    fn report_candidates(&mut self, filtered_dictionary: &[&str]) {
+        self.candidate_count = filtered_dictionary.len();
+        self.candidates = if filtered_dictionary.len() <= CANDIDATE_LISTING_THRESHOLD {
+            filtered_dictionary
+                .iter()
+                .map(|&word| word.to_owned())
+                .collect()
+        } else {
+            Vec::new()
+        };
+    }
+
+    /// In hard mode, once the candidate set is small, this picks letters
+    /// that distinguish between the remaining candidates (or finishes the
+    /// word outright once only one remains) instead of falling back to
+    /// frequency/entropy ranking. Returns `None` when hard mode isn't
+    /// active or the set is still too large to bother.
+    fn hard_mode_pick(&mut self, filtered_dictionary: &[&str]) -> Option<u8> {
+        let threshold = self.hard_mode_threshold?;
+        if filtered_dictionary.is_empty() || filtered_dictionary.len() > threshold {
+            return None;
+        }
+
+        if let [answer] = filtered_dictionary {
+            return answer
+                .bytes()
+                .find(|letter| !self.submitted.contains(letter));
+        }
+
+        self.rank_by_entropy(filtered_dictionary)
+            .choose(&mut self.rng)
+            .copied()
+    }
+
+    fn rank_by_frequency(&self, filtered_dictionary: &[&str]) -> Vec<u8> {
         let mut frequency = HashMap::new();
-        for u in filtered_dictionary.flat_map(|word| word.bytes()) {
+        for u in filtered_dictionary.iter().flat_map(|word| word.bytes()) {
             *frequency.entry(u).or_insert(0usize) += 1;
         }
 
@@ -90,27 +224,84 @@ impl SolverState {
             .collect();
         by_frequency.sort_unstable_by_key(|frequency| Reverse(frequency.1));
 
-        let first_rank: Vec<_> = first_rank_by_key(by_frequency, |frequency| frequency.1)
+        first_rank_by_key(by_frequency, |frequency| frequency.1)
             .map(|(value, _)| value)
+            .collect()
+    }
+
+    /// Ranks candidate letters by the Shannon entropy of the partition they
+    /// induce over `filtered_dictionary`: for each letter, words are grouped
+    /// by the bitmask of positions at which it occurs, and the entropy of
+    /// the resulting group sizes estimates how much the guess narrows things
+    /// down, regardless of how common the letter is overall. Relies on
+    /// `filtered_dictionary` being single-length (guaranteed by `Shape`'s
+    /// anchored regex), so a bit position always means the same thing across
+    /// every candidate word.
+    fn rank_by_entropy(&self, filtered_dictionary: &[&str]) -> Vec<u8> {
+        let n = filtered_dictionary.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut by_entropy: Vec<_> = (b'A'..=b'Z')
+            .filter(|c| !self.submitted.contains(c))
+            .map(|c| (c, letter_entropy(c, filtered_dictionary)))
             .collect();
+        by_entropy.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
-        let selected = first_rank
-            .choose(&mut self.rng)
-            .map(|&u| u)
-            .unwrap_or_else(|| (b'A'..=b'Z').choose(&mut self.rng).unwrap());
+        let top = by_entropy.first().map(|&(_, entropy)| entropy);
 
-        self.uncharacterized = Some(selected);
-        selected as char
+        // A zero (or near-zero) top entropy means no candidate letter splits
+        // the surviving words into more than one group - every letter looks
+        // equally (un)informative, most commonly because only one candidate
+        // is left. Entropy has nothing left to say at that point, so fall
+        // back to frequency, which only ever proposes letters that actually
+        // occur in the surviving words rather than tying all 26 uniformly.
+        if top.map_or(true, |entropy| entropy <= ENTROPY_EPSILON) {
+            return self.rank_by_frequency(filtered_dictionary);
+        }
+
+        let top = top.unwrap();
+        by_entropy
+            .into_iter()
+            .take_while(|&(_, entropy)| (entropy - top).abs() <= ENTROPY_EPSILON)
+            .map(|(c, _)| c)
+            .collect()
     }
 
-    fn characterize(&mut self, word: &str) {
+    /// Records whether the pending guess (if any) was a hit or a miss,
+    /// updating the filters future suggestions are ranked against.
+    fn record_feedback(&mut self, hit: bool) {
         if let Some(u) = self.uncharacterized.take() {
             self.submitted.insert(u);
-            if !word.bytes().any(|uword| u == uword) {
+            if !hit {
                 self.disallow.insert(u);
             }
         }
     }
+
+    /// Infers whether the pending guess was a hit from the server's
+    /// newly-revealed `word`, then records it. This is what lets the
+    /// server-driven `Solver` contract keep working without a caller
+    /// stating hit/miss explicitly.
+    fn characterize(&mut self, word: &str) {
+        let hit = self
+            .uncharacterized
+            .is_some_and(|u| word.bytes().any(|uword| u == uword));
+        self.record_feedback(hit);
+    }
+
+    /// Number of dictionary words still consistent with the guesses made so
+    /// far, as of the last `suggest` call.
+    fn candidate_count(&self) -> usize {
+        self.candidate_count
+    }
+
+    /// The surviving candidate words, populated once their count drops to
+    /// `CANDIDATE_LISTING_THRESHOLD` or fewer (empty otherwise).
+    fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
 }
 
 impl Default for SolverState {
@@ -119,13 +310,40 @@ impl Default for SolverState {
             submitted: Default::default(),
             uncharacterized: Default::default(),
             disallow: Default::default(),
+            strategy: Default::default(),
+            hard_mode_threshold: Default::default(),
 
             // Chosen by mashing keyboard. Plenty random.
             rng: SquirrelRng::with_seed(3408509824),
+            candidate_count: Default::default(),
+            candidates: Default::default(),
         }
     }
 }
 
+fn letter_entropy(letter: u8, words: &[&str]) -> f64 {
+    let n = words.len();
+    let mut groups: HashMap<u64, usize> = HashMap::new();
+    for word in words {
+        *groups.entry(position_mask(word, letter)).or_insert(0) += 1;
+    }
+
+    groups
+        .values()
+        .map(|&count| {
+            let p = count as f64 / n as f64;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn position_mask(word: &str, letter: u8) -> u64 {
+    word.bytes()
+        .enumerate()
+        .filter(|&(_, u)| u == letter)
+        .fold(0u64, |mask, (i, _)| mask | (1 << i))
+}
+
 fn first_rank_by_key<F, K, I: IntoIterator>(i: I, mut f: F) -> impl Iterator<Item = I::Item>
 where
     F: FnMut(&I::Item) -> K,
@@ -148,8 +366,10 @@ where
 }
 
 impl Solver for IntoStrategicSolver {
-    fn next_letter(&mut self, word: &str, guesses_remaining: usize) -> char {
-        self.state.next(word, guesses_remaining, &self.dictionary)
+    fn next_letter(&mut self, word: &str, _guesses_remaining: usize) -> char {
+        println!("{word}");
+        self.state.characterize(word);
+        self.state.suggest(word, &self.dictionary)
     }
 }
 
@@ -160,8 +380,133 @@ pub struct StrategicSolver<'a> {
 }
 
 impl Solver for StrategicSolver<'_> {
-    fn next_letter(&mut self, word: &str, guesses_remaining: usize) -> char {
-        self.state.next(word, guesses_remaining, &self.dictionary)
+    fn next_letter(&mut self, word: &str, _guesses_remaining: usize) -> char {
+        println!("{word}");
+        self.state.characterize(word);
+        self.state.suggest(word, &self.dictionary)
+    }
+}
+
+/// An interactive, server-free companion for a human playing hangman
+/// elsewhere: the caller supplies the revealed pattern and whether the last
+/// suggested letter hit or missed, rather than those being inferred from a
+/// server response.
+pub struct Assist {
+    dictionary: Arc<[String]>,
+    strategy: Strategy,
+    hard_mode_threshold: Option<usize>,
+    state: SolverState,
+    pattern: String,
+    history: Vec<Checkpoint>,
+}
+
+/// A snapshot of everything revealed-pattern feedback can change, taken
+/// before applying it so a guess can be undone. Bundles the REPL-facing
+/// `pattern` together with the solver's internal bookkeeping so the two
+/// can never drift out of sync across an undo.
+#[derive(Clone)]
+struct Checkpoint {
+    pattern: String,
+    submitted: HashSet<u8>,
+    uncharacterized: Option<u8>,
+    disallow: HashSet<u8>,
+}
+
+impl Assist {
+    /// The revealed pattern as currently known (`*` for unknown positions,
+    /// e.g. `"*A**A*"`).
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether every position in `pattern` has been revealed.
+    pub fn is_solved(&self) -> bool {
+        !self.pattern.is_empty() && !self.pattern.contains('*')
+    }
+
+    /// Suggests the next letter to guess given the currently revealed
+    /// pattern.
+    pub fn suggest(&mut self) -> char {
+        self.state.suggest(&self.pattern, &self.dictionary)
+    }
+
+    /// Number of dictionary words still consistent with the guesses made so
+    /// far, as of the last `suggest` call.
+    pub fn candidate_count(&self) -> usize {
+        self.state.candidate_count()
+    }
+
+    /// The surviving candidate words, populated once their count drops low
+    /// enough to be worth listing (empty otherwise).
+    pub fn candidates(&self) -> &[String] {
+        self.state.candidates()
+    }
+
+    /// Records the user-supplied revealed pattern, checkpointing the
+    /// previous one so `undo` can restore it alongside the solver state.
+    /// `pattern` is uppercased first; returns `false` without changing
+    /// anything if it contains any byte other than an ASCII letter or `*` -
+    /// after uppercasing, `A`-`Z` and `*` are the only characters
+    /// `build_expr` knows how to turn into a safe regex, and this is the
+    /// first point untrusted, hand-typed input enters the pipeline.
+    pub fn set_pattern(&mut self, pattern: impl Into<String>) -> bool {
+        let candidate = pattern.into().to_ascii_uppercase();
+        if !candidate
+            .bytes()
+            .all(|u| u == b'*' || u.is_ascii_uppercase())
+        {
+            return false;
+        }
+
+        self.checkpoint();
+        self.pattern = candidate;
+        true
+    }
+
+    /// Records that the last suggested letter was present in the word.
+    pub fn record_hit(&mut self) {
+        self.state.record_feedback(true);
+    }
+
+    /// Records that the last suggested letter was not present in the word.
+    pub fn record_miss(&mut self) {
+        self.state.record_feedback(false);
+    }
+
+    /// Undoes the most recent `set_pattern` and its accompanying
+    /// `record_hit`/`record_miss`, returning `false` if there's nothing
+    /// left to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(checkpoint) => {
+                self.pattern = checkpoint.pattern;
+                self.state.submitted = checkpoint.submitted;
+                self.state.uncharacterized = checkpoint.uncharacterized;
+                self.state.disallow = checkpoint.disallow;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resets all guesses, as if starting over on a word of a new `length`.
+    pub fn restart(&mut self, length: usize) {
+        self.state = SolverState {
+            strategy: self.strategy,
+            hard_mode_threshold: self.hard_mode_threshold,
+            ..Default::default()
+        };
+        self.pattern = "*".repeat(length);
+        self.history.clear();
+    }
+
+    fn checkpoint(&mut self) {
+        self.history.push(Checkpoint {
+            pattern: self.pattern.clone(),
+            submitted: self.state.submitted.clone(),
+            uncharacterized: self.state.uncharacterized,
+            disallow: self.state.disallow.clone(),
+        });
     }
 }
 
@@ -177,12 +522,15 @@ impl Shape<'_> {
 }
 
 fn build_expr(word: &str) -> Option<Regex> {
-    let expr: String = word
+    let body: String = word
         .bytes()
         .map(|u| match u {
             b'*' => b'.',
             u => u.to_ascii_uppercase(),
         } as char)
         .collect();
-    Regex::new(&expr).ok()
+
+    // Anchored so a revealed pattern only matches dictionary words of the
+    // same length, not any word that merely contains it as a substring.
+    Regex::new(&format!("^{body}$")).ok()
 }
@@ -6,7 +6,7 @@ use actix_web::{
 };
 use clap::Parser;
 use hangman::{CreateGameResponse, Game, UpdateGameRequest, UpdateGameResponse};
-use hashbrown::{HashMap, HashSet};
+use hashbrown::HashMap;
 use rand::{seq::SliceRandom, Rng};
 use squirrel_rng::SquirrelRng;
 use uuid::Uuid;
@@ -33,11 +33,17 @@ impl ResponseError for Error {
 struct Args {
     /// path to word list
     path: String,
+
+    /// play evil hangman: never commit to a word, just keep narrowing the
+    /// set of candidates consistent with the guesses made so far
+    #[clap(long)]
+    evil: bool,
 }
 
 struct AppStateWithGameDb {
     shared: Mutex<(SquirrelRng, HashMap<Uuid, Game>)>,
     word_list: Vec<String>,
+    evil: bool,
 }
 
 // FIXME: There is no reason whatsoever to use an async framework on this project. >.<
@@ -49,6 +55,7 @@ async fn main() -> io::Result<()> {
     let data = Data::new(AppStateWithGameDb {
         shared: Mutex::new((SquirrelRng::new(), HashMap::new())),
         word_list: read_words(&args.path)?,
+        evil: args.evil,
     });
 
     HttpServer::new(move || {
@@ -66,12 +73,11 @@ async fn main() -> io::Result<()> {
 async fn create_game(data: Data<AppStateWithGameDb>) -> io::Result<impl Responder> {
     let mut state = data.shared.lock().unwrap();
 
-    let game = build_game(&data.word_list, &mut state.0);
+    let game = build_game(&data.word_list, &mut state.0, data.evil);
     let id = Uuid::new_v4();
 
-    state.1.insert(id.clone(), game.clone());
-
     let response = CreateGameResponse::new(id, &game);
+    state.1.insert(id, game);
 
     Ok(web::Json(response))
 }
@@ -85,14 +91,14 @@ async fn read_game(id: web::Path<Uuid>, data: Data<AppStateWithGameDb>) -> Resul
 
     if game.is_lost() {
         return Ok(web::Json(UpdateGameResponse::lose(
-            &game.word,
+            game.answer(),
             "Better luck next time!",
         )));
     }
 
     if game.is_won() {
         return Ok(web::Json(UpdateGameResponse::win(
-            &game.word,
+            game.answer(),
             "I said you won! Stop rubbing it in. >.<",
         )));
     }
@@ -130,14 +136,14 @@ async fn update_game(
 
     if game.is_lost() {
         return Ok(web::Json(UpdateGameResponse::lose(
-            &game.word,
+            game.answer(),
             "Better luck next time!",
         )));
     }
 
     if game.is_won() {
         return Ok(web::Json(UpdateGameResponse::win(
-            &game.word,
+            game.answer(),
             "I said you won! Stop rubbing it in. >.<",
         )));
     }
@@ -147,29 +153,24 @@ async fn update_game(
 
     let guess = letter.bytes().next().unwrap().to_ascii_uppercase();
 
-    if game.correct.contains(&guess) || game.incorrect.contains(&guess) {
+    if game.correct().contains(&guess) || game.incorrect().contains(&guess) {
         return Err(Error::DuplicateGuess(letter));
     }
 
-    // Now, if the guess matches any character in the word, we will add that guess to the
-    // "correct" set. We'll then check again to see if the game has been won and respond
-    // accordingly.
+    // Now we hand the guess to the game itself: an honest game just checks the fixed word, while
+    // an evil game partitions its surviving candidates and keeps whichever group makes life
+    // hardest for the player. Either way we get back whether the guess was correct.
 
-    // If instead the guess fails to match anything, we add it to the "incorrect" set and check
-    // to see whether the user has just LOST the game. In that case, we will send him a nastygram
-    // and log this in his permanent file.
-
-    if game.word.bytes().any(|u| u == guess) {
-        game.correct.insert(guess);
+    if game.guess(guess) {
         if game.is_won() {
             if game.guesses_remaining() >= 3 {
                 return Ok(web::Json(UpdateGameResponse::win(
-                    &game.word,
+                    game.answer(),
                     "FLAWLESS VICTORY!",
                 )));
             } else {
                 return Ok(web::Json(UpdateGameResponse::win(
-                    &game.word,
+                    game.answer(),
                     "Victory is yours!",
                 )));
             }
@@ -177,10 +178,9 @@ async fn update_game(
 
         Ok(web::Json(UpdateGameResponse::update(game)))
     } else {
-        game.incorrect.insert(guess);
         if game.is_lost() {
             return Ok(web::Json(UpdateGameResponse::lose(
-                &game.word,
+                game.answer(),
                 "Sorry, friend. You've been hanged!",
             )));
         }
@@ -189,14 +189,22 @@ async fn update_game(
     }
 }
 
-fn build_game(words: &[String], rng: &mut impl Rng) -> Game {
-    Game {
-        word: words
+fn build_game(words: &[String], rng: &mut impl Rng, evil: bool) -> Game {
+    if evil {
+        let len = words
             .choose(rng)
             .expect("your word list is empty!")
-            .to_owned(),
-        correct: HashSet::new(),
-        incorrect: HashSet::new(),
+            .len();
+
+        Game::evil(
+            words
+                .iter()
+                .filter(|word| word.len() == len)
+                .cloned()
+                .collect(),
+        )
+    } else {
+        Game::honest(words.choose(rng).expect("your word list is empty!").to_owned())
     }
 }
 
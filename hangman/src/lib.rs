@@ -1,35 +1,176 @@
 use std::borrow::Cow;
 
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+const MAX_INCORRECT: i32 = 7;
+
+/// A game in progress. `Honest` commits to a single word up front, the way
+/// hangman is normally played. `Evil` instead holds every candidate word
+/// still consistent with the guesses made so far, and only pins down an
+/// actual answer once it's forced to.
+#[derive(Clone, Debug)]
+pub enum Game {
+    Honest(HonestGame),
+    Evil(EvilGame),
+}
+
 #[derive(Clone, Debug)]
-pub struct Game {
+pub struct HonestGame {
     pub word: String,
     pub correct: HashSet<u8>,
     pub incorrect: HashSet<u8>,
 }
 
+#[derive(Clone, Debug)]
+pub struct EvilGame {
+    pub candidates: Vec<String>,
+    pub revealed: HashMap<usize, u8>,
+    pub correct: HashSet<u8>,
+    pub incorrect: HashSet<u8>,
+}
+
 impl Game {
+    pub fn honest(word: String) -> Self {
+        Game::Honest(HonestGame {
+            word,
+            correct: HashSet::new(),
+            incorrect: HashSet::new(),
+        })
+    }
+
+    /// Starts an evil game over the given pool of same-length candidates.
+    pub fn evil(candidates: Vec<String>) -> Self {
+        Game::Evil(EvilGame {
+            candidates,
+            revealed: HashMap::new(),
+            correct: HashSet::new(),
+            incorrect: HashSet::new(),
+        })
+    }
+
+    pub fn correct(&self) -> &HashSet<u8> {
+        match self {
+            Game::Honest(game) => &game.correct,
+            Game::Evil(game) => &game.correct,
+        }
+    }
+
+    pub fn incorrect(&self) -> &HashSet<u8> {
+        match self {
+            Game::Honest(game) => &game.incorrect,
+            Game::Evil(game) => &game.incorrect,
+        }
+    }
+
     pub fn is_lost(&self) -> bool {
-        (7 - self.incorrect.len() as i32) <= 0
+        (MAX_INCORRECT - self.incorrect().len() as i32) <= 0
     }
 
     pub fn is_won(&self) -> bool {
-        self.word.bytes().all(|u| self.correct.contains(&u))
+        match self {
+            Game::Honest(game) => game.word.bytes().all(|u| game.correct.contains(&u)),
+            Game::Evil(game) => game.revealed.len() == word_len(game) && word_len(game) > 0,
+        }
     }
 
     pub fn masked_word(&self) -> String {
-        self.word
-            .bytes()
-            .map(|u| if self.correct.contains(&u) { u } else { b'*' } as char)
-            .collect()
+        match self {
+            Game::Honest(game) => game
+                .word
+                .bytes()
+                .map(|u| if game.correct.contains(&u) { u } else { b'*' } as char)
+                .collect(),
+            Game::Evil(game) => (0..word_len(game))
+                .map(|i| *game.revealed.get(&i).unwrap_or(&b'*') as char)
+                .collect(),
+        }
     }
 
     pub fn guesses_remaining(&self) -> i32 {
-        (7i32 - self.incorrect.len() as i32).max(0)
+        (MAX_INCORRECT - self.incorrect().len() as i32).max(0)
+    }
+
+    /// The word this game will ultimately reveal. An evil game still holding
+    /// more than one candidate pins its answer to the first survivor.
+    pub fn answer(&self) -> &str {
+        match self {
+            Game::Honest(game) => &game.word,
+            Game::Evil(game) => &game.candidates[0],
+        }
+    }
+
+    /// Applies a guessed letter, returning `true` if it was correct.
+    pub fn guess(&mut self, letter: u8) -> bool {
+        match self {
+            Game::Honest(game) => {
+                if game.word.bytes().any(|u| u == letter) {
+                    game.correct.insert(letter);
+                    true
+                } else {
+                    game.incorrect.insert(letter);
+                    false
+                }
+            }
+            Game::Evil(game) => game.guess(letter),
+        }
+    }
+}
+
+impl EvilGame {
+    fn guess(&mut self, letter: u8) -> bool {
+        let len = word_len(self);
+
+        let mut groups: HashMap<u64, Vec<String>> = HashMap::new();
+        for word in self.candidates.drain(..) {
+            let mask = position_mask(&word, letter);
+            groups.entry(mask).or_default().push(word);
+        }
+
+        let chosen_mask = adversarial_group(&groups);
+        self.candidates = groups.remove(&chosen_mask).unwrap_or_default();
+
+        let correct = chosen_mask != 0;
+        if correct {
+            for i in 0..len {
+                if chosen_mask & (1 << i) != 0 {
+                    self.revealed.insert(i, letter);
+                }
+            }
+            self.correct.insert(letter);
+        } else {
+            self.incorrect.insert(letter);
+        }
+
+        correct
+    }
+}
+
+/// Picks the surviving group that keeps the player suffering the longest:
+/// the absent-letter group whenever it's non-empty, otherwise the largest
+/// group, tie-breaking toward whichever reveals the fewest letters.
+fn adversarial_group(groups: &HashMap<u64, Vec<String>>) -> u64 {
+    if groups.get(&0).is_some_and(|words| !words.is_empty()) {
+        return 0;
     }
+
+    groups
+        .iter()
+        .max_by_key(|(mask, words)| (words.len(), std::cmp::Reverse(mask.count_ones())))
+        .map(|(&mask, _)| mask)
+        .unwrap_or(0)
+}
+
+fn position_mask(word: &str, letter: u8) -> u64 {
+    word.bytes()
+        .enumerate()
+        .filter(|&(_, u)| u == letter)
+        .fold(0u64, |mask, (i, _)| mask | (1 << i))
+}
+
+fn word_len(game: &EvilGame) -> usize {
+    game.candidates.first().map_or(0, |word| word.len())
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
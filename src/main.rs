@@ -5,7 +5,8 @@ use actix_web::{
     App, HttpServer, Responder,
 };
 use clap::Parser;
-use hashbrown::{HashMap, HashSet};
+use hangman::Game;
+use hashbrown::HashMap;
 use rand::{seq::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use squirrel_rng::SquirrelRng;
@@ -15,39 +16,17 @@ use uuid::Uuid;
 struct Args {
     /// path to word list
     path: String,
+
+    /// play evil hangman: never commit to a word, just keep narrowing the
+    /// set of candidates consistent with the guesses made so far
+    #[clap(long)]
+    evil: bool,
 }
 
 struct AppStateWithGameDb {
     shared: Mutex<(SquirrelRng, HashMap<Uuid, Game>)>,
     word_list: Vec<String>,
-}
-
-#[derive(Clone, Debug)]
-struct Game {
-    word: String,
-    correct: HashSet<u8>,
-    incorrect: HashSet<u8>,
-}
-
-impl Game {
-    fn is_lost(&self) -> bool {
-        (7 - self.incorrect.len() as i32) <= 0
-    }
-
-    fn is_won(&self) -> bool {
-        self.word.bytes().all(|u| self.correct.contains(&u))
-    }
-
-    fn masked_word(&self) -> String {
-        self.word
-            .bytes()
-            .map(|u| if self.correct.contains(&u) { u } else { b'*' } as char)
-            .collect()
-    }
-
-    fn guesses_remaining(&self) -> i32 {
-        (7i32 - self.incorrect.len() as i32).max(0)
-    }
+    evil: bool,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -81,6 +60,7 @@ async fn main() -> io::Result<()> {
     let data = Data::new(AppStateWithGameDb {
         shared: Mutex::new((SquirrelRng::new(), HashMap::new())),
         word_list: read_words(&args.path)?,
+        evil: args.evil,
     });
 
     HttpServer::new(move || {
@@ -97,17 +77,17 @@ async fn main() -> io::Result<()> {
 async fn new_game(data: Data<AppStateWithGameDb>) -> io::Result<impl Responder> {
     let mut state = data.shared.lock().expect("don't poison my lock, ok?");
 
-    let game = build_game(&data.word_list, &mut state.0);
+    let game = build_game(&data.word_list, &mut state.0, data.evil);
     let id = Uuid::new_v4();
 
-    state.1.insert(id.clone(), game.clone());
-
     let response = GameResponse {
         id,
         word: game.masked_word(),
         guesses: game.guesses_remaining(),
     };
 
+    state.1.insert(id, game);
+
     Ok(web::Json(response))
 }
 
@@ -149,14 +129,13 @@ async fn play_game(
         .expect("We just went over this...")
         .to_ascii_uppercase();
 
-    if game.correct.contains(&guess) {
+    if game.correct().contains(&guess) {
         return Ok(web::Json(PlayResponse::Illegal {
             message: "Your guesses must be unique.",
         }));
     }
 
-    if game.word.bytes().any(|u| u == guess) {
-        game.correct.insert(guess);
+    if game.guess(guess) {
         if game.is_won() {
             if game.guesses_remaining() >= 3 {
                 return Ok(web::Json(PlayResponse::Victory {
@@ -175,7 +154,6 @@ async fn play_game(
             guesses: game.guesses_remaining(),
         })))
     } else {
-        game.incorrect.insert(guess);
         if game.is_lost() {
             return Ok(web::Json(PlayResponse::Defeat {
                 message: "Sorry, friend. You've been hanged!",
@@ -190,14 +168,16 @@ async fn play_game(
     }
 }
 
-fn build_game(words: &[String], rng: &mut impl Rng) -> Game {
-    Game {
-        word: words
+fn build_game(words: &[String], rng: &mut impl Rng, evil: bool) -> Game {
+    if evil {
+        let len = words
             .choose(rng)
             .expect("your word list is empty!")
-            .to_owned(),
-        correct: HashSet::new(),
-        incorrect: HashSet::new(),
+            .len();
+
+        Game::evil(words.iter().filter(|word| word.len() == len).cloned().collect())
+    } else {
+        Game::honest(words.choose(rng).expect("your word list is empty!").to_owned())
     }
 }
 